@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use rust_decimal::Decimal;
+
+use crate::error::TransactionError;
+use crate::transaction::AccountId;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LedgerEntry {
+    Debit,
+    Credit,
+}
+
+#[derive(Debug, Clone)]
+struct LedgerRecord {
+    account: AccountId,
+    amount: Decimal,
+    entry: LedgerEntry,
+}
+
+/// Tracks per-account available deposits and gates transaction processing on
+/// having sufficient funds.
+///
+/// Every mutation is also appended to a ledger of debits/credits so balances
+/// can be recomputed from scratch to check for drift.
+#[derive(Debug, Default)]
+pub struct BalanceSheet {
+    balances: HashMap<AccountId, Decimal>,
+    ledger: Vec<LedgerRecord>,
+}
+
+impl BalanceSheet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_deposit(&mut self, account: AccountId, amount: Decimal) {
+        *self.balances.entry(account.clone()).or_insert(Decimal::ZERO) += amount;
+        self.ledger.push(LedgerRecord {
+            account,
+            amount,
+            entry: LedgerEntry::Credit,
+        });
+    }
+
+    pub fn balance_of(&self, account: &AccountId) -> Decimal {
+        self.balances.get(account).copied().unwrap_or(Decimal::ZERO)
+    }
+
+    /// Atomically checks that `account`'s deposit covers `amount + fee` and,
+    /// if so, debits it. Neither the balance nor the ledger are touched when
+    /// funds are insufficient.
+    pub fn debit(
+        &mut self,
+        account: &AccountId,
+        amount: Decimal,
+        fee: Decimal,
+    ) -> Result<(), TransactionError> {
+        let required = amount + fee;
+        let available = self.balance_of(account);
+        if available < required {
+            return Err(TransactionError::InsufficientFunds {
+                required,
+                available,
+            });
+        }
+
+        *self.balances.entry(account.clone()).or_insert(Decimal::ZERO) -= required;
+        self.ledger.push(LedgerRecord {
+            account: account.clone(),
+            amount: required,
+            entry: LedgerEntry::Debit,
+        });
+        Ok(())
+    }
+
+    /// Replays the ledger from scratch to recompute every account's balance,
+    /// independent of the incrementally-maintained `balances` map.
+    pub fn recompute(&self) -> HashMap<AccountId, Decimal> {
+        let mut recomputed: HashMap<AccountId, Decimal> = HashMap::new();
+        for record in &self.ledger {
+            let entry = recomputed.entry(record.account.clone()).or_insert(Decimal::ZERO);
+            match record.entry {
+                LedgerEntry::Credit => *entry += record.amount,
+                LedgerEntry::Debit => *entry -= record.amount,
+            }
+        }
+        recomputed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn debit_succeeds_when_funded() {
+        let mut sheet = BalanceSheet::new();
+        let alice = AccountId("alice".to_string());
+        sheet.record_deposit(alice.clone(), dec!(100));
+
+        sheet.debit(&alice, dec!(40), dec!(1)).unwrap();
+        assert_eq!(sheet.balance_of(&alice), dec!(59));
+    }
+
+    #[test]
+    fn debit_zero_amount_on_unfunded_account_does_not_panic() {
+        let mut sheet = BalanceSheet::new();
+        let bob = AccountId("bob".to_string());
+
+        sheet.debit(&bob, Decimal::ZERO, Decimal::ZERO).unwrap();
+        assert_eq!(sheet.balance_of(&bob), Decimal::ZERO);
+    }
+
+    #[test]
+    fn debit_rejects_when_underfunded() {
+        let mut sheet = BalanceSheet::new();
+        let alice = AccountId("alice".to_string());
+        sheet.record_deposit(alice.clone(), dec!(10));
+
+        let err = sheet.debit(&alice, dec!(40), dec!(0)).unwrap_err();
+        assert!(matches!(err, TransactionError::InsufficientFunds { .. }));
+        assert_eq!(sheet.balance_of(&alice), dec!(10), "failed debit must not mutate the balance");
+    }
+
+    #[test]
+    fn recompute_matches_incremental_balance() {
+        let mut sheet = BalanceSheet::new();
+        let alice = AccountId("alice".to_string());
+        sheet.record_deposit(alice.clone(), dec!(100));
+        sheet.debit(&alice, dec!(30), dec!(2)).unwrap();
+
+        assert_eq!(sheet.recompute().get(&alice).copied(), Some(sheet.balance_of(&alice)));
+    }
+}
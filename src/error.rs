@@ -0,0 +1,46 @@
+use rust_decimal::Decimal;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Failure modes for the transaction processing path.
+///
+/// Replaces the opaque `anyhow::Error` previously threaded through
+/// processing so callers can match on the concrete cause instead of
+/// string-sniffing a message.
+#[derive(Debug, Error)]
+pub enum TransactionError {
+    #[error("insufficient funds: required {required}, available {available}")]
+    InsufficientFunds {
+        required: Decimal,
+        available: Decimal,
+    },
+
+    #[error("invalid currency: {0}")]
+    InvalidCurrency(String),
+
+    #[error("duplicate transaction id: {0}")]
+    DuplicateId(Uuid),
+
+    #[error("amount out of range: {0}")]
+    AmountOutOfRange(Decimal),
+
+    #[error("transaction expired before settling")]
+    Expired,
+
+    #[error("transaction already processed")]
+    AlreadyProcessed,
+}
+
+impl TransactionError {
+    /// Process exit code `main` uses when this error surfaces at the top level.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            TransactionError::InsufficientFunds { .. } => 10,
+            TransactionError::InvalidCurrency(_) => 11,
+            TransactionError::DuplicateId(_) => 12,
+            TransactionError::AmountOutOfRange(_) => 13,
+            TransactionError::Expired => 14,
+            TransactionError::AlreadyProcessed => 15,
+        }
+    }
+}
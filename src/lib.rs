@@ -0,0 +1,7 @@
+pub mod balance;
+pub mod error;
+pub mod queue;
+pub mod serialize;
+pub mod store;
+pub mod swap;
+pub mod transaction;
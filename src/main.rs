@@ -1,93 +1,80 @@
 use anyhow::Result;
-use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use tokio::time::{sleep, Duration};
+use chrono::Utc;
+use rust_decimal_macros::dec;
+use tokio::time::Duration;
 use tracing::{info, warn};
 use uuid::Uuid;
 
-#[derive(Debug, Serialize, Deserialize)]
-struct Transaction {
-    id: Uuid,
-    amount: f64,
-    currency: String,
-    timestamp: DateTime<Utc>,
-    status: TransactionStatus,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-enum TransactionStatus {
-    Pending,
-    Completed,
-    Failed,
-}
+use rust_cargo_lock_corruption::balance::BalanceSheet;
+use rust_cargo_lock_corruption::queue::TransactionQueue;
+use rust_cargo_lock_corruption::store::TransactionStore;
+use rust_cargo_lock_corruption::transaction::{AccountId, PendingTransaction, Transaction, TransactionStatus};
 
 #[tokio::main]
 async fn main() -> Result<()> {
     tracing_subscriber::fmt::init();
-    
+
     info!("Starting financial processor");
-    
-    let mut transactions = HashMap::new();
-    
-    // Create a test transaction
+
+    let mut store = TransactionStore::new();
+    let mut queue = TransactionQueue::new(Duration::from_secs(300));
+    let mut balances = BalanceSheet::new();
+
+    let alice = AccountId("alice".to_string());
+    balances.record_deposit(alice.clone(), dec!(500.00));
+
+    // Submit a test transaction for sequencing.
     let tx = Transaction {
         id: Uuid::new_v4(),
-        amount: 100.50,
+        from: alice,
+        nonce: 0,
+        amount: dec!(100.50),
         currency: "USD".to_string(),
         timestamp: Utc::now(),
         status: TransactionStatus::Pending,
     };
-    
-    transactions.insert(tx.id, tx);
-    
-    // Simulate processing
-    sleep(Duration::from_millis(100)).await;
-    
-    info!("Processed {} transactions", transactions.len());
-    
+    let id = tx.id;
+    queue.submit(tx).expect("fresh nonce for a freshly created transaction");
+
+    // Drive every nonce-sequential transaction through confirmation polling
+    // and admission control.
+    for tx in queue.ready() {
+        let result = PendingTransaction::new(tx)
+            .with_poll_interval(Duration::from_millis(100))
+            .confirm(3, &mut balances, dec!(0))
+            .await
+            .and_then(|settled| store.insert(settled));
+
+        if let Err(err) = result {
+            warn!(error = %err, "transaction processing failed");
+            std::process::exit(err.exit_code());
+        }
+    }
+
+    if let Some(serialized) = store.get_transaction(id) {
+        info!(hash = %serialized.hash(), "settled transaction");
+    }
+
+    if let Some(raw) = store.get_raw_transaction(id, false) {
+        info!(?raw, "raw transaction lookup");
+    }
+
+    info!("Processed {} transactions", store.len());
+
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    #[test]
-    fn test_transaction_creation() {
-        let tx = Transaction {
-            id: Uuid::new_v4(),
-            amount: 50.0,
-            currency: "EUR".to_string(),
-            timestamp: Utc::now(),
-            status: TransactionStatus::Pending,
-        };
-        
-        assert_eq!(tx.amount, 50.0);
-        assert_eq!(tx.currency, "EUR");
-    }
-
-    #[test]
-    fn test_transaction_serialization() {
-        let tx = Transaction {
-            id: Uuid::new_v4(),
-            amount: 75.25,
-            currency: "GBP".to_string(),
-            timestamp: Utc::now(),
-            status: TransactionStatus::Completed,
-        };
-        
-        let json = serde_json::to_string(&tx).unwrap();
-        assert!(json.contains("75.25"));
-        assert!(json.contains("GBP"));
-    }
+    use tokio::time::sleep;
 
     #[tokio::test]
     async fn test_async_processing() {
         let start = std::time::Instant::now();
         sleep(Duration::from_millis(10)).await;
         let elapsed = start.elapsed();
-        
+
         assert!(elapsed >= Duration::from_millis(10));
     }
 }
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use thiserror::Error;
+
+use crate::transaction::{AccountId, Transaction};
+
+#[derive(Debug, Error)]
+pub enum QueueError {
+    #[error("nonce {nonce} is already buffered for this account")]
+    NonceCollision { nonce: u64 },
+}
+
+struct Entry {
+    tx: Transaction,
+    inserted_at: Instant,
+}
+
+/// Buffers submitted transactions per account and releases them for
+/// processing in strict nonce order.
+///
+/// A transaction becomes `ready` only once its nonce is exactly one greater
+/// than the last nonce released for that account; transactions that arrive
+/// ahead of the gap are held until it fills, and entries left unfilled past
+/// `ttl` are evicted so a missing nonce can't block an account forever.
+pub struct TransactionQueue {
+    last_processed: HashMap<AccountId, u64>,
+    pending: HashMap<AccountId, HashMap<u64, Entry>>,
+    ttl: Duration,
+}
+
+impl TransactionQueue {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            last_processed: HashMap::new(),
+            pending: HashMap::new(),
+            ttl,
+        }
+    }
+
+    /// Buffers a transaction. Call `ready()` to drain whatever has become
+    /// sequential as a result.
+    ///
+    /// Rejects a nonce that's already buffered for this account instead of
+    /// silently overwriting it — two distinct transactions racing for the
+    /// same nonce is exactly the reordering/replay hazard this queue exists
+    /// to prevent.
+    pub fn submit(&mut self, tx: Transaction) -> Result<(), QueueError> {
+        let account = tx.from.clone();
+        let bucket = self.pending.entry(account).or_default();
+        if bucket.contains_key(&tx.nonce) {
+            return Err(QueueError::NonceCollision { nonce: tx.nonce });
+        }
+
+        bucket.insert(
+            tx.nonce,
+            Entry {
+                tx,
+                inserted_at: Instant::now(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Evicts stale entries, then returns every transaction that is now
+    /// sequential with the last nonce processed for its account, in nonce
+    /// order, advancing the per-account cursor as it drains.
+    pub fn ready(&mut self) -> Vec<Transaction> {
+        self.evict_stale();
+
+        let mut drained = Vec::new();
+        for (account, queued) in self.pending.iter_mut() {
+            let mut next = self.last_processed.get(account).map_or(0, |n| n + 1);
+            let mut advanced = false;
+            while let Some(entry) = queued.remove(&next) {
+                drained.push(entry.tx);
+                next += 1;
+                advanced = true;
+            }
+            if advanced {
+                self.last_processed.insert(account.clone(), next - 1);
+            }
+        }
+
+        self.pending.retain(|_, queued| !queued.is_empty());
+        drained
+    }
+
+    fn evict_stale(&mut self) {
+        let ttl = self.ttl;
+        for queued in self.pending.values_mut() {
+            queued.retain(|_, entry| entry.inserted_at.elapsed() < ttl);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transaction::TransactionStatus;
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
+    use uuid::Uuid;
+
+    fn tx(account: &str, nonce: u64) -> Transaction {
+        Transaction {
+            id: Uuid::new_v4(),
+            from: AccountId(account.to_string()),
+            nonce,
+            amount: dec!(10.0),
+            currency: "USD".to_string(),
+            timestamp: Utc::now(),
+            status: TransactionStatus::Pending,
+        }
+    }
+
+    #[test]
+    fn releases_in_order_once_gap_fills() {
+        let mut queue = TransactionQueue::new(Duration::from_secs(60));
+        queue.submit(tx("alice", 1)).unwrap();
+        assert!(queue.ready().is_empty(), "nonce 1 is ahead of nonce 0");
+
+        queue.submit(tx("alice", 0)).unwrap();
+        let ready = queue.ready();
+        assert_eq!(ready.iter().map(|t| t.nonce).collect::<Vec<_>>(), vec![0, 1]);
+    }
+
+    #[test]
+    fn accounts_are_independent() {
+        let mut queue = TransactionQueue::new(Duration::from_secs(60));
+        queue.submit(tx("alice", 0)).unwrap();
+        queue.submit(tx("bob", 0)).unwrap();
+
+        let ready = queue.ready();
+        assert_eq!(ready.len(), 2);
+    }
+
+    #[test]
+    fn evicts_stale_future_nonces() {
+        let mut queue = TransactionQueue::new(Duration::from_millis(1));
+        queue.submit(tx("alice", 5)).unwrap();
+        std::thread::sleep(Duration::from_millis(10));
+
+        assert!(queue.ready().is_empty());
+        queue.submit(tx("alice", 0)).unwrap();
+        // nonce 5 should have been evicted, so only nonce 0 drains.
+        assert_eq!(queue.ready().iter().map(|t| t.nonce).collect::<Vec<_>>(), vec![0]);
+    }
+
+    #[test]
+    fn rejects_nonce_collision() {
+        let mut queue = TransactionQueue::new(Duration::from_secs(60));
+        queue.submit(tx("alice", 0)).unwrap();
+
+        let err = queue.submit(tx("alice", 0)).unwrap_err();
+        assert!(matches!(err, QueueError::NonceCollision { nonce: 0 }));
+    }
+}
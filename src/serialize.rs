@@ -0,0 +1,156 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::transaction::{AccountId, Transaction, TransactionStatus};
+
+/// Mirrors [`Transaction`] field-for-field, except `amount` is carried as
+/// `Decimal`'s raw 16-byte representation instead of going through
+/// `Decimal`'s own `serde` impl.
+///
+/// `Decimal::deserialize` (the `serde` one) calls `deserialize_any` to accept
+/// either a string or a number, which bincode's non-self-describing format
+/// doesn't support — every transaction would fail to decode. Routing through
+/// `Decimal::serialize()`/`Decimal::deserialize()` (the inherent, non-serde
+/// methods for its canonical byte form) keeps the rest of `Transaction`'s
+/// normal, human-readable `serde` derive untouched for JSON use elsewhere.
+#[derive(Serialize, Deserialize)]
+struct CanonicalTransaction {
+    id: Uuid,
+    from: AccountId,
+    nonce: u64,
+    amount: [u8; 16],
+    currency: String,
+    timestamp: DateTime<Utc>,
+    status: TransactionStatus,
+}
+
+impl From<&Transaction> for CanonicalTransaction {
+    fn from(tx: &Transaction) -> Self {
+        Self {
+            id: tx.id,
+            from: tx.from.clone(),
+            nonce: tx.nonce,
+            amount: tx.amount.serialize(),
+            currency: tx.currency.clone(),
+            timestamp: tx.timestamp,
+            status: tx.status.clone(),
+        }
+    }
+}
+
+impl From<CanonicalTransaction> for Transaction {
+    fn from(c: CanonicalTransaction) -> Self {
+        Self {
+            id: c.id,
+            from: c.from,
+            nonce: c.nonce,
+            amount: Decimal::deserialize(c.amount),
+            currency: c.currency,
+            timestamp: c.timestamp,
+            status: c.status,
+        }
+    }
+}
+
+/// Canonical, content-addressable encoding of a [`Transaction`].
+///
+/// Wraps a fixed binary layout (stable regardless of `serde_json` field
+/// ordering or whitespace) so the same logical transaction always produces
+/// the same bytes, and the bytes can be hashed to derive a content-addressed
+/// transaction hash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SerializedTransaction(Vec<u8>);
+
+impl SerializedTransaction {
+    /// Encodes a transaction into its canonical byte form.
+    pub fn encode(tx: &Transaction) -> Self {
+        let bytes = bincode::serialize(&CanonicalTransaction::from(tx))
+            .expect("transaction encoding is infallible");
+        Self(bytes)
+    }
+
+    /// Decodes the canonical bytes back into a [`Transaction`].
+    ///
+    /// Panics if the bytes were not produced by [`SerializedTransaction::encode`]
+    /// for a value of this `Transaction` shape.
+    pub fn decode(&self) -> Transaction {
+        let canonical: CanonicalTransaction =
+            bincode::deserialize(&self.0).expect("canonical bytes were produced by encode");
+        canonical.into()
+    }
+
+    pub fn as_hex(&self) -> String {
+        hex::encode(&self.0)
+    }
+
+    pub fn from_hex(hex_str: &str) -> Result<Self, hex::FromHexError> {
+        Ok(Self(hex::decode(hex_str)?))
+    }
+
+    /// Content-addressed hash of the canonical bytes, suitable for use as a
+    /// transaction hash alongside its `Uuid`.
+    pub fn hash(&self) -> String {
+        use sha2::{Digest, Sha256};
+        hex::encode(Sha256::digest(&self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transaction::{AccountId, TransactionStatus};
+    use chrono::{TimeZone, Utc};
+    use proptest::prelude::*;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+
+    fn arb_transaction() -> impl Strategy<Value = Transaction> {
+        (
+            any::<u128>(),
+            "[a-z]{1,8}",
+            any::<u64>(),
+            -100_000_000i64..100_000_000,
+            prop_oneof![Just("USD"), Just("EUR"), Just("GBP"), Just("JPY")],
+            0i64..2_000_000_000,
+            0u8..3,
+        )
+            .prop_map(|(id, from, nonce, amount, currency, secs, status)| Transaction {
+                id: Uuid::from_u128(id),
+                from: AccountId(from),
+                nonce,
+                amount: Decimal::new(amount, 2),
+                currency: currency.to_string(),
+                timestamp: Utc.timestamp_opt(secs, 0).unwrap(),
+                status: match status {
+                    0 => TransactionStatus::Pending,
+                    1 => TransactionStatus::Completed,
+                    _ => TransactionStatus::Failed,
+                },
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn roundtrips_through_canonical_bytes(tx in arb_transaction()) {
+            let serialized = SerializedTransaction::encode(&tx);
+            prop_assert_eq!(serialized.decode(), tx);
+        }
+
+        #[test]
+        fn roundtrips_through_hex(tx in arb_transaction()) {
+            let serialized = SerializedTransaction::encode(&tx);
+            let hex_str = serialized.as_hex();
+            let restored = SerializedTransaction::from_hex(&hex_str).unwrap();
+            prop_assert_eq!(restored, serialized);
+        }
+
+        #[test]
+        fn hash_is_stable_for_identical_transactions(tx in arb_transaction()) {
+            let a = SerializedTransaction::encode(&tx);
+            let b = SerializedTransaction::encode(&tx);
+            prop_assert_eq!(a.hash(), b.hash());
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::error::TransactionError;
+use crate::serialize::SerializedTransaction;
+use crate::transaction::Transaction;
+
+/// Either form a caller can request from [`TransactionStore::get_raw_transaction`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum RawTransaction {
+    /// Hex-encoded canonical bytes.
+    Hex(String),
+    /// The fully decoded transaction.
+    Decoded(Transaction),
+}
+
+/// In-memory, id-addressable store of transactions.
+#[derive(Debug, Default)]
+pub struct TransactionStore {
+    transactions: HashMap<Uuid, Transaction>,
+}
+
+impl TransactionStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a transaction, rejecting an id that's already present rather
+    /// than silently overwriting it.
+    pub fn insert(&mut self, tx: Transaction) -> Result<(), TransactionError> {
+        if self.transactions.contains_key(&tx.id) {
+            return Err(TransactionError::DuplicateId(tx.id));
+        }
+        self.transactions.insert(tx.id, tx);
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.transactions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.transactions.is_empty()
+    }
+
+    /// Looks up a transaction by id and returns its canonical encoding.
+    pub fn get_transaction(&self, id: Uuid) -> Option<SerializedTransaction> {
+        self.transactions.get(&id).map(SerializedTransaction::encode)
+    }
+
+    /// Looks up a transaction by id, returning either the hex-encoded
+    /// canonical bytes (`verbose = false`) or the fully decoded struct
+    /// (`verbose = true`).
+    pub fn get_raw_transaction(&self, id: Uuid, verbose: bool) -> Option<RawTransaction> {
+        let tx = self.transactions.get(&id)?;
+        Some(if verbose {
+            RawTransaction::Decoded(tx.clone())
+        } else {
+            RawTransaction::Hex(SerializedTransaction::encode(tx).as_hex())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transaction::{AccountId, TransactionStatus};
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
+
+    fn sample_tx() -> Transaction {
+        Transaction {
+            id: Uuid::new_v4(),
+            from: AccountId("alice".to_string()),
+            nonce: 0,
+            amount: dec!(42.0),
+            currency: "USD".to_string(),
+            timestamp: Utc::now(),
+            status: TransactionStatus::Pending,
+        }
+    }
+
+    #[test]
+    fn get_transaction_round_trips() {
+        let tx = sample_tx();
+        let mut store = TransactionStore::new();
+        store.insert(tx.clone()).unwrap();
+
+        let serialized = store.get_transaction(tx.id).unwrap();
+        assert_eq!(serialized.decode(), tx);
+    }
+
+    #[test]
+    fn insert_rejects_duplicate_id() {
+        let tx = sample_tx();
+        let mut store = TransactionStore::new();
+        store.insert(tx.clone()).unwrap();
+
+        let err = store.insert(tx.clone()).unwrap_err();
+        assert!(matches!(err, TransactionError::DuplicateId(id) if id == tx.id));
+    }
+
+    #[test]
+    fn get_raw_transaction_respects_verbose_flag() {
+        let tx = sample_tx();
+        let mut store = TransactionStore::new();
+        store.insert(tx.clone()).unwrap();
+
+        match store.get_raw_transaction(tx.id, true).unwrap() {
+            RawTransaction::Decoded(decoded) => assert_eq!(decoded, tx),
+            RawTransaction::Hex(_) => panic!("expected a decoded transaction"),
+        }
+
+        match store.get_raw_transaction(tx.id, false).unwrap() {
+            RawTransaction::Hex(hex_str) => {
+                assert_eq!(SerializedTransaction::from_hex(&hex_str).unwrap().decode(), tx)
+            }
+            RawTransaction::Decoded(_) => panic!("expected hex-encoded bytes"),
+        }
+    }
+
+    #[test]
+    fn get_transaction_missing_id_returns_none() {
+        let store = TransactionStore::new();
+        assert!(store.get_transaction(Uuid::new_v4()).is_none());
+    }
+}
@@ -0,0 +1,280 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use tokio::sync::Mutex;
+use tokio::time::sleep;
+
+use crate::transaction::{Transaction, TransactionStatus};
+
+#[derive(Debug, Error)]
+pub enum SwapError {
+    #[error("revealed secret does not match the locked hash")]
+    SecretMismatch,
+    #[error("both legs must be locked before they can be redeemed")]
+    LegNotLocked,
+    #[error("swap timed out before the secret was revealed; legs refunded")]
+    TimedOut,
+}
+
+/// Where one leg of a [`Swap`] sits in the hash-time-lock lifecycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LegState {
+    Unlocked,
+    Locked,
+    Redeemed,
+    Refunded,
+}
+
+#[derive(Debug)]
+struct Leg {
+    tx: Transaction,
+    state: LegState,
+}
+
+/// Settles two transactions in different currencies as an all-or-nothing
+/// unit, HTLC-style.
+///
+/// Both legs are locked behind the hash of a shared secret; `settle` polls
+/// until the secret is `reveal`ed or `timeout` elapses, redeeming both legs
+/// on reveal or refunding any locked leg on timeout. Because each leg's state
+/// is a plain field, a coordinator that crashes mid-settlement can
+/// reconstruct an in-flight swap with [`Swap::resume`] and safely redeem
+/// whichever leg didn't settle before the crash — redeeming an
+/// already-`Redeemed` leg is a no-op, so no partial settlement is ever
+/// double-applied.
+#[derive(Debug)]
+pub struct Swap {
+    leg_a: Leg,
+    leg_b: Leg,
+    secret_hash: [u8; 32],
+    secret: Option<[u8; 32]>,
+    poll_interval: Duration,
+    deadline: Instant,
+}
+
+impl Swap {
+    pub fn new(leg_a: Transaction, leg_b: Transaction, secret_hash: [u8; 32], timeout: Duration) -> Self {
+        Self {
+            leg_a: Leg {
+                tx: leg_a,
+                state: LegState::Unlocked,
+            },
+            leg_b: Leg {
+                tx: leg_b,
+                state: LegState::Unlocked,
+            },
+            secret_hash,
+            secret: None,
+            poll_interval: Duration::from_millis(50),
+            deadline: Instant::now() + timeout,
+        }
+    }
+
+    /// Reconstructs a swap already in progress, e.g. after a coordinator
+    /// restart, from its persisted leg states and hash lock.
+    pub fn resume(
+        leg_a: Transaction,
+        leg_a_state: LegState,
+        leg_b: Transaction,
+        leg_b_state: LegState,
+        secret_hash: [u8; 32],
+        timeout: Duration,
+    ) -> Self {
+        let mut swap = Self::new(leg_a, leg_b, secret_hash, timeout);
+        swap.leg_a.state = leg_a_state;
+        swap.leg_b.state = leg_b_state;
+        swap
+    }
+
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    pub fn secret_hash(&self) -> [u8; 32] {
+        self.secret_hash
+    }
+
+    /// Reveals the secret behind the hash lock, unblocking `settle`'s redeem
+    /// step. May be called before or after `settle` has locked the legs;
+    /// `settle` always locks both legs before it redeems them.
+    pub fn reveal(&mut self, secret: [u8; 32]) -> Result<(), SwapError> {
+        let digest: [u8; 32] = Sha256::digest(secret).into();
+        if digest != self.secret_hash {
+            return Err(SwapError::SecretMismatch);
+        }
+        self.secret = Some(secret);
+        Ok(())
+    }
+
+    fn lock_both(&mut self) {
+        if self.leg_a.state == LegState::Unlocked {
+            self.leg_a.state = LegState::Locked;
+        }
+        if self.leg_b.state == LegState::Unlocked {
+            self.leg_b.state = LegState::Locked;
+        }
+    }
+
+    /// Locks both legs, then polls on a fixed backoff until `reveal` supplies
+    /// the secret or the timeout elapses, at which point any locked leg is
+    /// refunded instead.
+    ///
+    /// Takes `swap` behind an `Arc<Mutex<_>>` rather than `self` so a
+    /// concurrent task can call [`Swap::reveal`] on the same swap while this
+    /// future is polling — the lock is only held for the duration of each
+    /// check, not across the `sleep` between them.
+    pub async fn settle(swap: Arc<Mutex<Swap>>) -> Result<(Transaction, Transaction), SwapError> {
+        swap.lock().await.lock_both();
+
+        loop {
+            let mut guard = swap.lock().await;
+            if guard.secret.is_some() {
+                return guard.redeem();
+            }
+            if Instant::now() >= guard.deadline {
+                guard.refund();
+                return Err(SwapError::TimedOut);
+            }
+            let poll_interval = guard.poll_interval;
+            drop(guard);
+            sleep(poll_interval).await;
+        }
+    }
+
+    fn redeem(&mut self) -> Result<(Transaction, Transaction), SwapError> {
+        for leg in [&self.leg_a, &self.leg_b] {
+            if !matches!(leg.state, LegState::Locked | LegState::Redeemed) {
+                return Err(SwapError::LegNotLocked);
+            }
+        }
+
+        for leg in [&mut self.leg_a, &mut self.leg_b] {
+            if leg.state == LegState::Locked {
+                leg.state = LegState::Redeemed;
+                leg.tx.status = TransactionStatus::Completed;
+            }
+        }
+
+        Ok((self.leg_a.tx.clone(), self.leg_b.tx.clone()))
+    }
+
+    fn refund(&mut self) {
+        for leg in [&mut self.leg_a, &mut self.leg_b] {
+            if leg.state == LegState::Locked {
+                leg.state = LegState::Refunded;
+                leg.tx.status = TransactionStatus::Failed;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transaction::AccountId;
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
+    use uuid::Uuid;
+
+    fn leg(account: &str, currency: &str) -> Transaction {
+        Transaction {
+            id: Uuid::new_v4(),
+            from: AccountId(account.to_string()),
+            nonce: 0,
+            amount: dec!(100),
+            currency: currency.to_string(),
+            timestamp: Utc::now(),
+            status: TransactionStatus::Pending,
+        }
+    }
+
+    fn secret_and_hash() -> ([u8; 32], [u8; 32]) {
+        let secret = [7u8; 32];
+        let hash: [u8; 32] = Sha256::digest(secret).into();
+        (secret, hash)
+    }
+
+    #[tokio::test]
+    async fn settles_both_legs_on_reveal() {
+        let (secret, hash) = secret_and_hash();
+        let swap = Arc::new(Mutex::new(
+            Swap::new(leg("alice", "USD"), leg("bob", "EUR"), hash, Duration::from_secs(5))
+                .with_poll_interval(Duration::from_millis(1)),
+        ));
+        swap.lock().await.reveal(secret).unwrap();
+
+        let (a, b) = Swap::settle(swap).await.unwrap();
+        assert!(matches!(a.status, TransactionStatus::Completed));
+        assert!(matches!(b.status, TransactionStatus::Completed));
+    }
+
+    #[tokio::test]
+    async fn refunds_both_legs_on_timeout() {
+        let (_, hash) = secret_and_hash();
+        let swap = Arc::new(Mutex::new(
+            Swap::new(leg("alice", "USD"), leg("bob", "EUR"), hash, Duration::from_millis(5))
+                .with_poll_interval(Duration::from_millis(1)),
+        ));
+
+        let err = Swap::settle(Arc::clone(&swap)).await.unwrap_err();
+        assert!(matches!(err, SwapError::TimedOut));
+        let swap = swap.lock().await;
+        assert_eq!(swap.leg_a.state, LegState::Refunded);
+        assert_eq!(swap.leg_b.state, LegState::Refunded);
+    }
+
+    #[test]
+    fn reveal_rejects_wrong_secret() {
+        let (_, hash) = secret_and_hash();
+        let mut swap = Swap::new(leg("alice", "USD"), leg("bob", "EUR"), hash, Duration::from_secs(5));
+
+        let err = swap.reveal([0u8; 32]).unwrap_err();
+        assert!(matches!(err, SwapError::SecretMismatch));
+    }
+
+    #[tokio::test]
+    async fn resume_completes_only_the_unsettled_leg() {
+        let (secret, hash) = secret_and_hash();
+        let swap = Arc::new(Mutex::new(
+            Swap::resume(
+                leg("alice", "USD"),
+                LegState::Redeemed,
+                leg("bob", "EUR"),
+                LegState::Locked,
+                hash,
+                Duration::from_secs(5),
+            )
+            .with_poll_interval(Duration::from_millis(1)),
+        ));
+        swap.lock().await.reveal(secret).unwrap();
+
+        let (a, b) = Swap::settle(swap).await.unwrap();
+        assert!(matches!(a.status, TransactionStatus::Pending), "already-redeemed leg is untouched");
+        assert!(matches!(b.status, TransactionStatus::Completed));
+    }
+
+    #[tokio::test]
+    async fn settle_observes_a_reveal_that_happens_mid_poll() {
+        let (secret, hash) = secret_and_hash();
+        let swap = Arc::new(Mutex::new(
+            Swap::new(leg("alice", "USD"), leg("bob", "EUR"), hash, Duration::from_secs(5))
+                .with_poll_interval(Duration::from_millis(1)),
+        ));
+
+        let revealer = {
+            let swap = Arc::clone(&swap);
+            tokio::spawn(async move {
+                sleep(Duration::from_millis(20)).await;
+                swap.lock().await.reveal(secret).unwrap();
+            })
+        };
+
+        let (a, b) = Swap::settle(Arc::clone(&swap)).await.unwrap();
+        revealer.await.unwrap();
+        assert!(matches!(a.status, TransactionStatus::Completed));
+        assert!(matches!(b.status, TransactionStatus::Completed));
+    }
+}
@@ -0,0 +1,281 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
+use uuid::Uuid;
+
+use crate::balance::BalanceSheet;
+use crate::error::TransactionError;
+
+/// Identifies the sending account of a [`Transaction`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AccountId(pub String);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Transaction {
+    pub id: Uuid,
+    pub from: AccountId,
+    pub nonce: u64,
+    pub amount: Decimal,
+    pub currency: String,
+    pub timestamp: DateTime<Utc>,
+    pub status: TransactionStatus,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TransactionStatus {
+    Pending,
+    Completed,
+    Failed,
+}
+
+/// Currencies the processor knows how to settle.
+const SUPPORTED_CURRENCIES: &[&str] = &["USD", "EUR", "GBP", "JPY"];
+
+/// Where a [`PendingTransaction`] sits in its confirmation lifecycle.
+///
+/// `AwaitingConfirmations` carries the confirmation count seen so far so the
+/// state machine can be resumed (e.g. after a restart) without losing progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmationState {
+    Submitted,
+    AwaitingConfirmations(u32),
+    Completed,
+    Failed,
+}
+
+/// Drives a [`Transaction`] from submission through confirmation polling to a
+/// terminal `Completed`/`Failed` status.
+///
+/// `confirm` polls on a fixed backoff until `target` confirmations accrue or
+/// `timeout` elapses, at which point the transaction is deterministically
+/// marked `Failed` rather than left to hang. Before the transaction is
+/// allowed to settle, its sender's balance is debited for `amount` plus a
+/// fee; insufficient funds also fail the transaction.
+#[derive(Debug)]
+pub struct PendingTransaction {
+    tx: Transaction,
+    state: ConfirmationState,
+    confirmations: u32,
+    poll_interval: Duration,
+    timeout: Duration,
+}
+
+impl PendingTransaction {
+    pub fn new(tx: Transaction) -> Self {
+        Self {
+            tx,
+            state: ConfirmationState::Submitted,
+            confirmations: 0,
+            poll_interval: Duration::from_millis(100),
+            timeout: Duration::from_secs(30),
+        }
+    }
+
+    /// Resumes a state machine that already accrued `confirmations`, e.g. after
+    /// a process restart.
+    pub fn resume(tx: Transaction, confirmations: u32) -> Self {
+        let mut pending = Self::new(tx);
+        pending.confirmations = confirmations;
+        pending.state = ConfirmationState::AwaitingConfirmations(confirmations);
+        pending
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    pub fn state(&self) -> ConfirmationState {
+        self.state
+    }
+
+    pub fn confirmations(&self) -> u32 {
+        self.confirmations
+    }
+
+    /// Polls until `target` confirmations accrue or the configured timeout
+    /// elapses. Once confirmed, debits `balances` for the transaction amount
+    /// plus `fee` before transitioning the stored status to `Completed`; an
+    /// underfunded sender fails the transaction instead.
+    ///
+    /// Rejects up front a transaction that isn't `Pending` (already
+    /// processed), whose currency isn't one the processor settles, or whose
+    /// amount is zero, negative, or implausibly large.
+    pub async fn confirm(
+        mut self,
+        target: u32,
+        balances: &mut BalanceSheet,
+        fee: Decimal,
+    ) -> Result<Transaction, TransactionError> {
+        if !matches!(self.tx.status, TransactionStatus::Pending) {
+            return Err(TransactionError::AlreadyProcessed);
+        }
+        if !SUPPORTED_CURRENCIES.contains(&self.tx.currency.as_str()) {
+            self.state = ConfirmationState::Failed;
+            self.tx.status = TransactionStatus::Failed;
+            return Err(TransactionError::InvalidCurrency(self.tx.currency.clone()));
+        }
+        if self.tx.amount <= Decimal::ZERO || self.tx.amount > Decimal::from(1_000_000) {
+            self.state = ConfirmationState::Failed;
+            self.tx.status = TransactionStatus::Failed;
+            return Err(TransactionError::AmountOutOfRange(self.tx.amount));
+        }
+
+        self.state = ConfirmationState::AwaitingConfirmations(self.confirmations);
+        let deadline = Instant::now() + self.timeout;
+
+        while self.confirmations < target {
+            if Instant::now() >= deadline {
+                self.state = ConfirmationState::Failed;
+                self.tx.status = TransactionStatus::Failed;
+                return Err(TransactionError::Expired);
+            }
+
+            sleep(self.poll_interval).await;
+            self.confirmations += 1;
+            self.state = ConfirmationState::AwaitingConfirmations(self.confirmations);
+        }
+
+        if let Err(err) = balances.debit(&self.tx.from, self.tx.amount, fee) {
+            self.state = ConfirmationState::Failed;
+            self.tx.status = TransactionStatus::Failed;
+            return Err(err);
+        }
+
+        self.state = ConfirmationState::Completed;
+        self.tx.status = TransactionStatus::Completed;
+        Ok(self.tx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn sample_tx() -> Transaction {
+        Transaction {
+            id: Uuid::new_v4(),
+            from: AccountId("alice".to_string()),
+            nonce: 0,
+            amount: dec!(50.0),
+            currency: "EUR".to_string(),
+            timestamp: Utc::now(),
+            status: TransactionStatus::Pending,
+        }
+    }
+
+    fn funded_balances(account: &AccountId, amount: Decimal) -> BalanceSheet {
+        let mut balances = BalanceSheet::new();
+        balances.record_deposit(account.clone(), amount);
+        balances
+    }
+
+    #[test]
+    fn test_transaction_creation() {
+        let tx = sample_tx();
+        assert_eq!(tx.amount, dec!(50.0));
+        assert_eq!(tx.currency, "EUR");
+    }
+
+    #[test]
+    fn test_transaction_serialization() {
+        let mut tx = sample_tx();
+        tx.amount = dec!(75.25);
+        tx.currency = "GBP".to_string();
+        tx.status = TransactionStatus::Completed;
+
+        let json = serde_json::to_string(&tx).unwrap();
+        assert!(json.contains("75.25"));
+        assert!(json.contains("GBP"));
+    }
+
+    #[tokio::test]
+    async fn test_confirm_reaches_completed() {
+        let tx = sample_tx();
+        let mut balances = funded_balances(&tx.from, dec!(1000));
+        let pending = PendingTransaction::new(tx)
+            .with_poll_interval(Duration::from_millis(1))
+            .with_timeout(Duration::from_secs(5));
+
+        let tx = pending.confirm(3, &mut balances, dec!(0)).await.unwrap();
+        assert!(matches!(tx.status, TransactionStatus::Completed));
+    }
+
+    #[tokio::test]
+    async fn test_confirm_times_out() {
+        let tx = sample_tx();
+        let mut balances = funded_balances(&tx.from, dec!(1000));
+        let pending = PendingTransaction::new(tx)
+            .with_poll_interval(Duration::from_millis(20))
+            .with_timeout(Duration::from_millis(5));
+
+        let err = pending.confirm(100, &mut balances, dec!(0)).await.unwrap_err();
+        assert!(matches!(err, TransactionError::Expired));
+    }
+
+    #[tokio::test]
+    async fn test_confirm_fails_when_underfunded() {
+        let tx = sample_tx();
+        let mut balances = BalanceSheet::new();
+        let pending = PendingTransaction::new(tx)
+            .with_poll_interval(Duration::from_millis(1))
+            .with_timeout(Duration::from_secs(5));
+
+        let err = pending.confirm(1, &mut balances, dec!(0)).await.unwrap_err();
+        assert!(matches!(err, TransactionError::InsufficientFunds { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_confirm_rejects_invalid_currency() {
+        let mut tx = sample_tx();
+        tx.currency = "XYZ".to_string();
+        let mut balances = funded_balances(&tx.from, dec!(1000));
+        let pending = PendingTransaction::new(tx).with_poll_interval(Duration::from_millis(1));
+
+        let err = pending.confirm(1, &mut balances, dec!(0)).await.unwrap_err();
+        assert!(matches!(err, TransactionError::InvalidCurrency(currency) if currency == "XYZ"));
+    }
+
+    #[tokio::test]
+    async fn test_confirm_rejects_amount_out_of_range() {
+        let mut tx = sample_tx();
+        tx.amount = dec!(0);
+        let mut balances = funded_balances(&tx.from, dec!(1000));
+        let pending = PendingTransaction::new(tx).with_poll_interval(Duration::from_millis(1));
+
+        let err = pending.confirm(1, &mut balances, dec!(0)).await.unwrap_err();
+        assert!(matches!(err, TransactionError::AmountOutOfRange(_)));
+    }
+
+    #[tokio::test]
+    async fn test_confirm_rejects_already_processed() {
+        let mut tx = sample_tx();
+        tx.status = TransactionStatus::Completed;
+        let mut balances = funded_balances(&tx.from, dec!(1000));
+        let pending = PendingTransaction::new(tx).with_poll_interval(Duration::from_millis(1));
+
+        let err = pending.confirm(1, &mut balances, dec!(0)).await.unwrap_err();
+        assert!(matches!(err, TransactionError::AlreadyProcessed));
+    }
+
+    #[tokio::test]
+    async fn test_resume_keeps_prior_confirmations() {
+        let tx = sample_tx();
+        let mut balances = funded_balances(&tx.from, dec!(1000));
+        let pending = PendingTransaction::resume(tx, 2)
+            .with_poll_interval(Duration::from_millis(1))
+            .with_timeout(Duration::from_secs(5));
+
+        assert_eq!(pending.confirmations(), 2);
+        let tx = pending.confirm(3, &mut balances, dec!(0)).await.unwrap();
+        assert!(matches!(tx.status, TransactionStatus::Completed));
+    }
+}